@@ -1,12 +1,47 @@
 //! Types and constants for handling electrical charge.
 
 use super::measurement::*;
+pub use quantity::Charge;
+use quantity::{Float, Quantity};
+
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::num::ParseFloatError;
+#[cfg(not(feature = "std"))]
+use core::num::ParseFloatError;
+
+#[cfg(feature = "std")]
+use std::str::FromStr;
+#[cfg(not(feature = "std"))]
+use core::str::FromStr;
+
+#[cfg(not(feature = "std"))]
+use alloc::string::{String, ToString};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Magnitude of speed of light (m/s)
 pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
 
-/// The `Charge` struct can be used to deal with electrical charge in a
-/// common way.
+// Every `Charge` conversion below is plain `+`/`-`/`*`/`/`, so unlike
+// measurements that need `sqrt`/`powf`/`cbrt`/`log10` (see `Float`'s
+// `no_std` shim in `quantity.rs`), this module has no transcendental math of
+// its own. It still touches `std`/`core`/`alloc` directly above for `fmt`,
+// `ParseFloatError`, `FromStr`, and the owned `String` in
+// `ChargeParseError`, so those imports stay conditional on the `std`
+// feature.
+
+/// `Charge` is a dimensional alias for `Quantity<_, _, P1, P1, _, _, _>` -
+/// its type tags it as "time to the power of one, times current to the
+/// power of one" (coulomb = ampere * second). See the [`quantity`] module
+/// for how the dimension tracking works and [`Current`](quantity::Current)
+/// / [`Time`](quantity::Time) for the quantities that combine to produce
+/// one.
 ///
 /// # Example
 ///
@@ -18,49 +53,44 @@ pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
 /// let ab_c = ch.as_abcoulombs();
 /// println!("A charge of {} C has {} abC", c, ab_c);
 /// ```
-#[derive(Copy, Clone, Debug)]
-pub struct Charge {
-    coulombs: f64,
-}
-
-impl Charge {
+impl<F: Float> Charge<F> {
     /// Create a new Charge from a floating point value in coulombs
-    pub fn from_coulombs(coulombs: f64) -> Self {
-        Charge { coulombs }
+    pub fn from_coulombs(coulombs: F) -> Self {
+        Quantity::from_base_units(coulombs)
     }
 
     /// Create a new Charge from a floating point value in abcoulombs
-    pub fn from_abcoulombs(abcoulombs: f64) -> Self {
-        Self::from_coulombs(abcoulombs * 10.0)
+    pub fn from_abcoulombs(abcoulombs: F) -> Self {
+        Self::from_coulombs(abcoulombs * F::from_f64(10.0))
     }
 
     /// Create a new Charge from a floating point value in coulombs
-    pub fn from_statcoulombs(statcoulombs: f64) -> Self {
-        Self::from_coulombs(statcoulombs / (10.0 * SPEED_OF_LIGHT))
+    pub fn from_statcoulombs(statcoulombs: F) -> Self {
+        Self::from_coulombs(statcoulombs / F::from_f64(10.0 * SPEED_OF_LIGHT))
     }
 
     /// Convert this Charge into a floating point value in statcoulombs
-    pub fn as_coulombs(&self) -> f64 {
-        self.coulombs
+    pub fn as_coulombs(&self) -> F {
+        self.as_base_units()
     }
 
     /// Convert this Charge into a floating point value in abcoulombs
-    pub fn as_abcoulombs(&self) -> f64 {
-        self.coulombs / 10.0
+    pub fn as_abcoulombs(&self) -> F {
+        self.as_base_units() / F::from_f64(10.0)
     }
 
     /// Convert this Charge into a floating point value in statcoulombs
-    pub fn as_statcoulombs(&self) -> f64 {
-        self.coulombs * (10.0 * SPEED_OF_LIGHT)
+    pub fn as_statcoulombs(&self) -> F {
+        self.as_base_units() * F::from_f64(10.0 * SPEED_OF_LIGHT)
     }
 }
 
-impl Measurement for Charge {
-    fn as_base_units(&self) -> f64 {
-        self.coulombs
+impl<F: Float> Measurement<F> for Charge<F> {
+    fn as_base_units(&self) -> F {
+        Quantity::as_base_units(self)
     }
 
-    fn from_base_units(units: f64) -> Self {
+    fn from_base_units(units: F) -> Self {
         Self::from_coulombs(units)
     }
 
@@ -68,7 +98,7 @@ impl Measurement for Charge {
         "C"
     }
 
-    fn get_appropriate_units(&self) -> (&'static str, f64) {
+    fn get_appropriate_units(&self) -> (&'static str, F) {
         // Smallest to Largest
         let list = [
             ("fC", 1e-15),
@@ -84,15 +114,172 @@ impl Measurement for Charge {
             ("PC", 1e15),
             ("EC", 1e18),
         ];
+        let list = list.map(|(name, factor)| (name, F::from_f64(factor)));
         self.pick_appropriate_units(&list)
     }
 }
 
-implement_measurement! { Charge }
+implement_display! { Charge }
+
+/// Error returned when parsing a `Charge` from a string via [`FromStr`]
+/// fails - either the numeric part isn't a valid float, or the unit
+/// suffix isn't one `Charge` recognises.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChargeParseError {
+    /// The numeric portion (everything before the unit suffix) wasn't a
+    /// valid floating point number.
+    InvalidNumber(ParseFloatError),
+    /// The unit suffix wasn't one of the recognised `Charge` units (e.g.
+    /// `mC`, `kC`, `abC`, `statC`).
+    UnknownUnit(String),
+}
+
+impl fmt::Display for ChargeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ChargeParseError::InvalidNumber(ref e) => write!(f, "invalid charge value: {}", e),
+            ChargeParseError::UnknownUnit(ref u) => write!(f, "unknown charge unit: {:?}", u),
+        }
+    }
+}
+
+impl From<ParseFloatError> for ChargeParseError {
+    fn from(e: ParseFloatError) -> Self {
+        ChargeParseError::InvalidNumber(e)
+    }
+}
+
+/// Length of the leading numeric run (optional sign, digits, optional
+/// `.digits`, optional `e`/`E` exponent) at the start of `s`, so the unit
+/// suffix after it can be split off cleanly.
+///
+/// A bare predicate like "digit or one of `+-.eE`" would treat `e`/`E` as
+/// numeric unconditionally, which misparses a no-space exa-coulomb value
+/// like `"5EC"` as `"5E"` / `"C"`. Instead, `e`/`E` is only consumed here
+/// when it's actually followed by an (optionally signed) digit, i.e. when
+/// it really starts an exponent.
+fn numeric_prefix_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let len = bytes.len();
+    let mut i = 0;
+    if i < len && (bytes[i] == b'+' || bytes[i] == b'-') {
+        i += 1;
+    }
+    while i < len && bytes[i].is_ascii_digit() {
+        i += 1;
+    }
+    if i < len && bytes[i] == b'.' {
+        i += 1;
+        while i < len && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+    }
+    if i < len && (bytes[i] == b'e' || bytes[i] == b'E') {
+        let mut j = i + 1;
+        if j < len && (bytes[j] == b'+' || bytes[j] == b'-') {
+            j += 1;
+        }
+        let exponent_digits_start = j;
+        while j < len && bytes[j].is_ascii_digit() {
+            j += 1;
+        }
+        if j > exponent_digits_start {
+            i = j;
+        }
+    }
+    i
+}
+
+impl<F: Float> FromStr for Charge<F> {
+    type Err = ChargeParseError;
+
+    /// Parse a string like `"72 mC"`, `"1.5 kC"`, or `"599584.916 statC"`
+    /// into a `Charge`. The unit suffix is matched against the same table
+    /// `get_appropriate_units` uses, plus the non-SI `abC`/`statC` names;
+    /// an absent suffix is taken to mean plain coulombs.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (value_str, unit_str) = s.split_at(numeric_prefix_len(s));
+        let value: f64 = value_str.trim().parse()?;
+        let factor = match unit_str.trim() {
+            "fC" => 1e-15,
+            "pC" => 1e-12,
+            "nC" => 1e-9,
+            "\u{00B5}C" | "uC" => 1e-6,
+            "mC" => 1e-3,
+            "C" | "" => 1e0,
+            "kC" => 1e3,
+            "MC" => 1e6,
+            "GC" => 1e9,
+            "TC" => 1e12,
+            "PC" => 1e15,
+            "EC" => 1e18,
+            "abC" => return Ok(Charge::from_abcoulombs(F::from_f64(value))),
+            "statC" => return Ok(Charge::from_statcoulombs(F::from_f64(value))),
+            other => return Err(ChargeParseError::UnknownUnit(other.to_string())),
+        };
+        Ok(Charge::from_coulombs(F::from_f64(value * factor)))
+    }
+}
+
+// `Charge` is a type alias over the generic `Quantity`, so it can't pick up
+// a blanket `#[derive(Serialize)]` for every dimension at once (the field
+// name depends on the measurement's own base unit). The pattern each
+// measurement follows when it opts in to `serde` is its own small
+// hand-written impl, serializing/deserializing as the canonical base-unit
+// value - here, `{"coulombs": 72.0}` - so round-tripping never loses
+// precision to an intermediate "appropriate" unit.
+//
+// This patch only touches `Charge`; `Current`/`Time`/`Voltage`/`Energy`
+// (and any other `Quantity` alias) would need the same impl added
+// separately before "serde support for all measurement types" is actually
+// complete.
+#[cfg(feature = "serde")]
+impl<F: Float> Serialize for Charge<F> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Charge", 1)?;
+        state.serialize_field("coulombs", &self.as_coulombs().to_f64())?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, F: Float> Deserialize<'de> for Charge<F> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct ChargeData {
+            coulombs: f64,
+        }
+        let data = ChargeData::deserialize(deserializer)?;
+        Ok(Charge::from_coulombs(F::from_f64(data.coulombs)))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_test {
+    use charge::Charge;
+
+    #[test]
+    fn round_trips_through_json() {
+        let original = Charge::from_coulombs(72.0);
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(json, r#"{"coulombs":72.0}"#);
+        let back: Charge = serde_json::from_str(&json).unwrap();
+        assert_eq!(original, back);
+    }
+}
 
 #[cfg(test)]
 mod test {
     use charge::*;
+    use quantity::{Current, Time};
     use test_utils::assert_almost_eq;
 
     #[test]
@@ -133,7 +320,7 @@ mod test {
     #[test]
     fn mul() {
         let a = Charge::from_coulombs(10.0);
-        let b = 4.0 * a;
+        let b: Charge = 4.0 * a;
         assert_almost_eq(b.as_abcoulombs(), 4.0);
     }
 
@@ -143,7 +330,7 @@ mod test {
         let b = Charge::from_coulombs(40.0);
         let c = a / b;
         let d = a / 2.0;
-        assert_almost_eq(c, 0.5);
+        assert_almost_eq(c.as_ratio(), 0.5);
         assert_almost_eq(d.as_coulombs(), 10.0);
     }
 
@@ -170,4 +357,66 @@ mod test {
         assert_eq!(a > b, false);
         assert_eq!(a >= b, false);
     }
+
+    #[test]
+    fn current_times_time_is_charge() {
+        let i = Current::from_amperes(5.0);
+        let t = Time::from_seconds(3.0);
+        let q: Charge = i * t;
+        assert_almost_eq(q.as_coulombs(), 15.0);
+    }
+
+    #[test]
+    fn f32_backed_charge_is_a_measurement() {
+        let c: Charge<f32> = Charge::from_coulombs(72.0f32);
+        assert_eq!(c.get_base_units_name(), "C");
+        assert_eq!(format!("{}", c), "72 C");
+    }
+
+    #[test]
+    fn parses_milli_suffix() {
+        let c: Charge = "72 mC".parse().unwrap();
+        assert_almost_eq(c.as_coulombs(), 0.072);
+    }
+
+    #[test]
+    fn parses_kilo_suffix() {
+        let c: Charge = "1.5 kC".parse().unwrap();
+        assert_almost_eq(c.as_coulombs(), 1500.0);
+    }
+
+    #[test]
+    fn parses_statcoulombs() {
+        let c: Charge = "599584.916 statC".parse().unwrap();
+        assert_almost_eq(c.as_coulombs(), 0.0002);
+    }
+
+    #[test]
+    fn parses_abcoulombs() {
+        let c: Charge = "4 abC".parse().unwrap();
+        assert_almost_eq(c.as_coulombs(), 40.0);
+    }
+
+    #[test]
+    fn parses_exa_suffix_without_space() {
+        let c: Charge = "5EC".parse().unwrap();
+        assert_almost_eq(c.as_coulombs(), 5e18);
+    }
+
+    #[test]
+    fn parses_bare_coulombs() {
+        let c: Charge = "10".parse().unwrap();
+        assert_almost_eq(c.as_coulombs(), 10.0);
+    }
+
+    #[test]
+    fn rejects_unknown_unit() {
+        let err = "5 banana".parse::<Charge>().unwrap_err();
+        assert_eq!(err, ChargeParseError::UnknownUnit("banana".to_string()));
+    }
+
+    #[test]
+    fn rejects_malformed_number() {
+        assert!("not-a-number mC".parse::<Charge>().is_err());
+    }
 }
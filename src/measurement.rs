@@ -0,0 +1,59 @@
+//! The `Measurement` trait shared by every unit-of-measure type in this
+//! crate, plus the small `implement_display!` helper that wires a type's
+//! [`Measurement::get_appropriate_units`] up to `Display`.
+
+use quantity::Float;
+
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(not(feature = "std"))]
+use core::fmt;
+
+/// Common behaviour for every measurement type (`Charge`, `Current`, ...),
+/// generic over the backing [`Float`] so `Charge<f32>` is as much a
+/// `Measurement` as the default `Charge` (= `Charge<f64>`).
+pub trait Measurement<F: Float = f64> {
+    /// The raw value in this measurement's SI base units.
+    fn as_base_units(&self) -> F;
+    /// Build a new value from a quantity already in SI base units.
+    fn from_base_units(units: F) -> Self;
+    /// The abbreviated name of the SI base unit, e.g. `"C"` for coulombs.
+    fn get_base_units_name(&self) -> &'static str;
+    /// The unit (and corresponding value) that best fits the magnitude of
+    /// this measurement, e.g. `("mC", 72.0)` rather than `("C", 0.072)`.
+    fn get_appropriate_units(&self) -> (&'static str, F);
+
+    /// Shared implementation backing `get_appropriate_units`: walk `list`
+    /// (ordered smallest unit to largest) and return the largest unit whose
+    /// threshold the magnitude of the base-unit value clears, alongside the
+    /// value rescaled into it.
+    fn pick_appropriate_units(&self, list: &[(&'static str, F)]) -> (&'static str, F) {
+        let base = self.as_base_units();
+        let zero = F::from_f64(0.0);
+        let magnitude = if base < zero { zero - base } else { base };
+        let mut result = (self.get_base_units_name(), base);
+        for &(name, factor) in list {
+            if magnitude >= factor {
+                result = (name, base / factor);
+            }
+        }
+        result
+    }
+}
+
+/// Implements `Display` for `$t<F>` in terms of `Measurement::get_appropriate_units`.
+///
+/// Display-only: `Quantity` already provides blanket `Add`/`Sub`/`Mul`/`Div`
+/// for every dimension, so this macro (unlike the old, pre-`Quantity`
+/// `implement_measurement!`) must not re-emit those operator impls - doing
+/// so would conflict with the blanket ones.
+macro_rules! implement_display {
+    ($t:ident) => {
+        impl<F: Float + fmt::Display> fmt::Display for $t<F> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let (name, value) = self.get_appropriate_units();
+                write!(f, "{} {}", value, name)
+            }
+        }
+    };
+}
@@ -0,0 +1,727 @@
+//! Compile-time dimensional analysis.
+//!
+//! A [`Quantity`] carries its physical dimension (length, mass, time,
+//! current, temperature, amount of substance, luminous intensity) as seven
+//! type parameters, each one of the zero-sized [`Dim`] markers below. The
+//! markers are just signed exponents lifted to the type level: `P1` means
+//! "to the power of one", `N1` means "to the power of minus one", and so
+//! on. `Add`/`Sub` only type-check when both sides share every exponent,
+//! while `Mul`/`Div` combine two (possibly different) dimensions by adding
+//! or subtracting their exponents, one dimension at a time, via the
+//! [`DimAdd`]/[`DimSub`] tables further down.
+//!
+//! This mirrors the ISQ (International System of Quantities): charge is
+//! literally "current times time", voltage is "energy per charge", and so
+//! on - the compiler checks the physics for you instead of trusting that
+//! every call site multiplied the right things together.
+//!
+//! The value itself is generic over the backing [`Float`] type (`f32` or
+//! `f64`, defaulting to `f64`), independently of the dimension tags.
+
+// `core`, not `std`: the dimension bookkeeping here is pure type-level
+// arithmetic over a single `F` value; the only `std` float methods used are
+// those behind the `Float` impls below, which switch to `libm` without the
+// `std` feature, so this module stays usable from a `#![no_std]` crate.
+use core::marker::PhantomData;
+use core::ops::{Add, Div, Mul, Sub};
+
+/// The float type backing a [`Quantity`]'s value.
+///
+/// Implemented for `f32` and `f64` so callers who need a smaller footprint
+/// (more values per cache line, more lanes per SSE/AVX register) can opt
+/// into `Quantity<.., f32>` instead of paying for a `f64` they don't need.
+///
+/// `sqrt`/`powf`/`cbrt`/`log10` are routed through the inherent `f32`/`f64`
+/// methods when the `std` feature is enabled, and through `libm` otherwise,
+/// so modules built on top of `Float` (e.g. geometry-ish measurements
+/// needing `sqrt`) keep working under `#![no_std]`.
+pub trait Float:
+    Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// Widen/narrow an `f64` literal (e.g. a conversion factor) into `Self`.
+    fn from_f64(value: f64) -> Self;
+    /// Widen `Self` back out to an `f64`, e.g. for display or interop.
+    fn to_f64(self) -> f64;
+    /// Square root.
+    fn sqrt(self) -> Self;
+    /// `self` raised to the power `n`.
+    fn powf(self, n: Self) -> Self;
+    /// Cube root.
+    fn cbrt(self) -> Self;
+    /// Base-10 logarithm.
+    fn log10(self) -> Self;
+}
+
+impl Float for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[cfg(feature = "std")]
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+    #[cfg(not(feature = "std"))]
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn powf(self, n: Self) -> Self {
+        f32::powf(self, n)
+    }
+    #[cfg(not(feature = "std"))]
+    fn powf(self, n: Self) -> Self {
+        libm::powf(self, n)
+    }
+
+    #[cfg(feature = "std")]
+    fn cbrt(self) -> Self {
+        f32::cbrt(self)
+    }
+    #[cfg(not(feature = "std"))]
+    fn cbrt(self) -> Self {
+        libm::cbrtf(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn log10(self) -> Self {
+        f32::log10(self)
+    }
+    #[cfg(not(feature = "std"))]
+    fn log10(self) -> Self {
+        libm::log10f(self)
+    }
+}
+
+impl Float for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[cfg(feature = "std")]
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+    #[cfg(not(feature = "std"))]
+    fn sqrt(self) -> Self {
+        libm::sqrt(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn powf(self, n: Self) -> Self {
+        f64::powf(self, n)
+    }
+    #[cfg(not(feature = "std"))]
+    fn powf(self, n: Self) -> Self {
+        libm::pow(self, n)
+    }
+
+    #[cfg(feature = "std")]
+    fn cbrt(self) -> Self {
+        f64::cbrt(self)
+    }
+    #[cfg(not(feature = "std"))]
+    fn cbrt(self) -> Self {
+        libm::cbrt(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn log10(self) -> Self {
+        f64::log10(self)
+    }
+    #[cfg(not(feature = "std"))]
+    fn log10(self) -> Self {
+        libm::log10(self)
+    }
+}
+
+/// A type-level signed exponent used to tag one of the seven SI base
+/// dimensions on a [`Quantity`].
+pub trait Dim {}
+
+/// Adding two type-level exponents, as required when multiplying two
+/// `Quantity` values together.
+pub trait DimAdd<Rhs: Dim>: Dim {
+    /// The resulting exponent.
+    type Output: Dim;
+}
+
+/// Subtracting two type-level exponents, as required when dividing one
+/// `Quantity` by another.
+pub trait DimSub<Rhs: Dim>: Dim {
+    /// The resulting exponent.
+    type Output: Dim;
+}
+
+macro_rules! dim_marker {
+    ($name:ident) => {
+        /// A type-level dimension exponent.
+        #[derive(Copy, Clone, Debug)]
+        pub struct $name;
+        impl Dim for $name {}
+    };
+}
+
+dim_marker!(N3);
+dim_marker!(N2);
+dim_marker!(N1);
+dim_marker!(Z0);
+dim_marker!(P1);
+dim_marker!(P2);
+dim_marker!(P3);
+
+impl DimAdd<Z0> for N3 {
+    type Output = N3;
+}
+impl DimAdd<P1> for N3 {
+    type Output = N2;
+}
+impl DimAdd<P2> for N3 {
+    type Output = N1;
+}
+impl DimAdd<P3> for N3 {
+    type Output = Z0;
+}
+impl DimAdd<N1> for N2 {
+    type Output = N3;
+}
+impl DimAdd<Z0> for N2 {
+    type Output = N2;
+}
+impl DimAdd<P1> for N2 {
+    type Output = N1;
+}
+impl DimAdd<P2> for N2 {
+    type Output = Z0;
+}
+impl DimAdd<P3> for N2 {
+    type Output = P1;
+}
+impl DimAdd<N2> for N1 {
+    type Output = N3;
+}
+impl DimAdd<N1> for N1 {
+    type Output = N2;
+}
+impl DimAdd<Z0> for N1 {
+    type Output = N1;
+}
+impl DimAdd<P1> for N1 {
+    type Output = Z0;
+}
+impl DimAdd<P2> for N1 {
+    type Output = P1;
+}
+impl DimAdd<P3> for N1 {
+    type Output = P2;
+}
+impl DimAdd<N3> for Z0 {
+    type Output = N3;
+}
+impl DimAdd<N2> for Z0 {
+    type Output = N2;
+}
+impl DimAdd<N1> for Z0 {
+    type Output = N1;
+}
+impl DimAdd<Z0> for Z0 {
+    type Output = Z0;
+}
+impl DimAdd<P1> for Z0 {
+    type Output = P1;
+}
+impl DimAdd<P2> for Z0 {
+    type Output = P2;
+}
+impl DimAdd<P3> for Z0 {
+    type Output = P3;
+}
+impl DimAdd<N3> for P1 {
+    type Output = N2;
+}
+impl DimAdd<N2> for P1 {
+    type Output = N1;
+}
+impl DimAdd<N1> for P1 {
+    type Output = Z0;
+}
+impl DimAdd<Z0> for P1 {
+    type Output = P1;
+}
+impl DimAdd<P1> for P1 {
+    type Output = P2;
+}
+impl DimAdd<P2> for P1 {
+    type Output = P3;
+}
+impl DimAdd<N3> for P2 {
+    type Output = N1;
+}
+impl DimAdd<N2> for P2 {
+    type Output = Z0;
+}
+impl DimAdd<N1> for P2 {
+    type Output = P1;
+}
+impl DimAdd<Z0> for P2 {
+    type Output = P2;
+}
+impl DimAdd<P1> for P2 {
+    type Output = P3;
+}
+impl DimAdd<N3> for P3 {
+    type Output = Z0;
+}
+impl DimAdd<N2> for P3 {
+    type Output = P1;
+}
+impl DimAdd<N1> for P3 {
+    type Output = P2;
+}
+impl DimAdd<Z0> for P3 {
+    type Output = P3;
+}
+
+impl DimSub<N3> for N3 {
+    type Output = Z0;
+}
+impl DimSub<N2> for N3 {
+    type Output = N1;
+}
+impl DimSub<N1> for N3 {
+    type Output = N2;
+}
+impl DimSub<Z0> for N3 {
+    type Output = N3;
+}
+impl DimSub<N3> for N2 {
+    type Output = P1;
+}
+impl DimSub<N2> for N2 {
+    type Output = Z0;
+}
+impl DimSub<N1> for N2 {
+    type Output = N1;
+}
+impl DimSub<Z0> for N2 {
+    type Output = N2;
+}
+impl DimSub<P1> for N2 {
+    type Output = N3;
+}
+impl DimSub<N3> for N1 {
+    type Output = P2;
+}
+impl DimSub<N2> for N1 {
+    type Output = P1;
+}
+impl DimSub<N1> for N1 {
+    type Output = Z0;
+}
+impl DimSub<Z0> for N1 {
+    type Output = N1;
+}
+impl DimSub<P1> for N1 {
+    type Output = N2;
+}
+impl DimSub<P2> for N1 {
+    type Output = N3;
+}
+impl DimSub<N3> for Z0 {
+    type Output = P3;
+}
+impl DimSub<N2> for Z0 {
+    type Output = P2;
+}
+impl DimSub<N1> for Z0 {
+    type Output = P1;
+}
+impl DimSub<Z0> for Z0 {
+    type Output = Z0;
+}
+impl DimSub<P1> for Z0 {
+    type Output = N1;
+}
+impl DimSub<P2> for Z0 {
+    type Output = N2;
+}
+impl DimSub<P3> for Z0 {
+    type Output = N3;
+}
+impl DimSub<N2> for P1 {
+    type Output = P3;
+}
+impl DimSub<N1> for P1 {
+    type Output = P2;
+}
+impl DimSub<Z0> for P1 {
+    type Output = P1;
+}
+impl DimSub<P1> for P1 {
+    type Output = Z0;
+}
+impl DimSub<P2> for P1 {
+    type Output = N1;
+}
+impl DimSub<P3> for P1 {
+    type Output = N2;
+}
+impl DimSub<N1> for P2 {
+    type Output = P3;
+}
+impl DimSub<Z0> for P2 {
+    type Output = P2;
+}
+impl DimSub<P1> for P2 {
+    type Output = P1;
+}
+impl DimSub<P2> for P2 {
+    type Output = Z0;
+}
+impl DimSub<P3> for P2 {
+    type Output = N1;
+}
+impl DimSub<Z0> for P3 {
+    type Output = P3;
+}
+impl DimSub<P1> for P3 {
+    type Output = P2;
+}
+impl DimSub<P2> for P3 {
+    type Output = P1;
+}
+impl DimSub<P3> for P3 {
+    type Output = Z0;
+}
+
+/// A physical quantity tagged, at the type level, with its dimension in
+/// terms of the seven SI base quantities: Length, Mass, Time, electric
+/// Current, absolute Temperature ("Theta"), amount of substance (N,
+/// moles), and luminous Intensity (J, candela).
+///
+/// The value is always stored in SI base units for that dimension (e.g. a
+/// `Quantity` tagged as charge stores coulombs). `Mul`/`Div` combine the
+/// dimensions of their operands; `Add`/`Sub` require both sides to already
+/// share the same dimension.
+///
+/// The value itself is backed by `F` (defaulting to `f64`, as everywhere
+/// else in this crate) - see [`Float`].
+#[derive(Copy, Clone, Debug)]
+pub struct Quantity<L, M, T, I, Th, N, J, F = f64> {
+    value: F,
+    dimension: PhantomData<(L, M, T, I, Th, N, J)>,
+}
+
+impl<L, M, T, I, Th, N, J, F: Float> Quantity<L, M, T, I, Th, N, J, F> {
+    /// Create a new `Quantity` directly from a value in SI base units.
+    pub(crate) fn from_base_units(value: F) -> Self {
+        Quantity {
+            value,
+            dimension: PhantomData,
+        }
+    }
+
+    /// The raw value in SI base units for this dimension.
+    pub(crate) fn as_base_units(&self) -> F {
+        self.value
+    }
+}
+
+impl<L, M, T, I, Th, N, J, F: Float> Add for Quantity<L, M, T, I, Th, N, J, F> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Quantity::from_base_units(self.value + rhs.value)
+    }
+}
+
+impl<L, M, T, I, Th, N, J, F: Float> Sub for Quantity<L, M, T, I, Th, N, J, F> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Quantity::from_base_units(self.value - rhs.value)
+    }
+}
+
+impl<L, M, T, I, Th, N, J, F: Float> PartialEq for Quantity<L, M, T, I, Th, N, J, F> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.value == rhs.value
+    }
+}
+
+impl<L, M, T, I, Th, N, J, F: Float> PartialOrd for Quantity<L, M, T, I, Th, N, J, F> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<::core::cmp::Ordering> {
+        self.value.partial_cmp(&rhs.value)
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<L1, M1, T1, I1, Th1, N1_, J1, L2, M2, T2, I2, Th2, N2_, J2, F: Float>
+    Mul<Quantity<L2, M2, T2, I2, Th2, N2_, J2, F>> for Quantity<L1, M1, T1, I1, Th1, N1_, J1, F>
+where
+    L1: DimAdd<L2>,
+    M1: DimAdd<M2>,
+    T1: DimAdd<T2>,
+    I1: DimAdd<I2>,
+    Th1: DimAdd<Th2>,
+    N1_: DimAdd<N2_>,
+    J1: DimAdd<J2>,
+    L2: Dim,
+    M2: Dim,
+    T2: Dim,
+    I2: Dim,
+    Th2: Dim,
+    N2_: Dim,
+    J2: Dim,
+{
+    type Output = Quantity<
+        <L1 as DimAdd<L2>>::Output,
+        <M1 as DimAdd<M2>>::Output,
+        <T1 as DimAdd<T2>>::Output,
+        <I1 as DimAdd<I2>>::Output,
+        <Th1 as DimAdd<Th2>>::Output,
+        <N1_ as DimAdd<N2_>>::Output,
+        <J1 as DimAdd<J2>>::Output,
+        F,
+    >;
+
+    // Not `Self::Output`: `Quantity` also implements `Mul<F>` (scalar
+    // multiplication), so with two `Mul` impls in scope the unqualified
+    // associated type is ambiguous - spell out the concrete `Output` type
+    // from just above instead.
+    fn mul(
+        self,
+        rhs: Quantity<L2, M2, T2, I2, Th2, N2_, J2, F>,
+    ) -> Quantity<
+        <L1 as DimAdd<L2>>::Output,
+        <M1 as DimAdd<M2>>::Output,
+        <T1 as DimAdd<T2>>::Output,
+        <I1 as DimAdd<I2>>::Output,
+        <Th1 as DimAdd<Th2>>::Output,
+        <N1_ as DimAdd<N2_>>::Output,
+        <J1 as DimAdd<J2>>::Output,
+        F,
+    > {
+        Quantity::from_base_units(self.value * rhs.value)
+    }
+}
+
+#[allow(clippy::type_complexity)]
+impl<L1, M1, T1, I1, Th1, N1_, J1, L2, M2, T2, I2, Th2, N2_, J2, F: Float>
+    Div<Quantity<L2, M2, T2, I2, Th2, N2_, J2, F>> for Quantity<L1, M1, T1, I1, Th1, N1_, J1, F>
+where
+    L1: DimSub<L2>,
+    M1: DimSub<M2>,
+    T1: DimSub<T2>,
+    I1: DimSub<I2>,
+    Th1: DimSub<Th2>,
+    N1_: DimSub<N2_>,
+    J1: DimSub<J2>,
+    L2: Dim,
+    M2: Dim,
+    T2: Dim,
+    I2: Dim,
+    Th2: Dim,
+    N2_: Dim,
+    J2: Dim,
+{
+    type Output = Quantity<
+        <L1 as DimSub<L2>>::Output,
+        <M1 as DimSub<M2>>::Output,
+        <T1 as DimSub<T2>>::Output,
+        <I1 as DimSub<I2>>::Output,
+        <Th1 as DimSub<Th2>>::Output,
+        <N1_ as DimSub<N2_>>::Output,
+        <J1 as DimSub<J2>>::Output,
+        F,
+    >;
+
+    // See the matching note on `Mul` above: `Quantity` also implements
+    // `Div<F>`, so `Self::Output` would be ambiguous here.
+    fn div(
+        self,
+        rhs: Quantity<L2, M2, T2, I2, Th2, N2_, J2, F>,
+    ) -> Quantity<
+        <L1 as DimSub<L2>>::Output,
+        <M1 as DimSub<M2>>::Output,
+        <T1 as DimSub<T2>>::Output,
+        <I1 as DimSub<I2>>::Output,
+        <Th1 as DimSub<Th2>>::Output,
+        <N1_ as DimSub<N2_>>::Output,
+        <J1 as DimSub<J2>>::Output,
+        F,
+    > {
+        Quantity::from_base_units(self.value / rhs.value)
+    }
+}
+
+impl<L, M, T, I, Th, N, J, F: Float> Mul<F> for Quantity<L, M, T, I, Th, N, J, F> {
+    type Output = Self;
+    fn mul(self, rhs: F) -> Self {
+        Quantity::from_base_units(self.value * rhs)
+    }
+}
+
+impl<L, M, T, I, Th, N, J, F: Float> Div<F> for Quantity<L, M, T, I, Th, N, J, F> {
+    type Output = Self;
+    fn div(self, rhs: F) -> Self {
+        Quantity::from_base_units(self.value / rhs)
+    }
+}
+
+// `F: Float` alone can't carry a blanket `impl<F: Float> Mul<Quantity<..,
+// F>> for F`, since `F` here stands for a foreign type (`f32`/`f64`) and the
+// orphan rules only allow that when the impl is concrete on one of our two
+// `Float` implementors. One macro, one arm per float, keeps `4.0 * charge`
+// working left-to-right alongside the already-generic `charge * 4.0`.
+macro_rules! impl_scalar_lhs_mul {
+    ($float:ty) => {
+        impl<L, M, T, I, Th, N, J> Mul<Quantity<L, M, T, I, Th, N, J, $float>> for $float {
+            type Output = Quantity<L, M, T, I, Th, N, J, $float>;
+            fn mul(self, rhs: Quantity<L, M, T, I, Th, N, J, $float>) -> Self::Output {
+                rhs * self
+            }
+        }
+    };
+}
+impl_scalar_lhs_mul!(f32);
+impl_scalar_lhs_mul!(f64);
+
+// Dimension aliases for the quantities this crate cross-references from
+// `Quantity`. Each one fixes the seven exponents (Length, Mass, Time,
+// Current, Theta, N, J) to those of its SI unit, and stays generic over the
+// backing float so e.g. `Current<f32>` is available alongside the default
+// `Current` (= `Current<f64>`).
+/// Electric current (A), dimension I^1.
+pub type Current<F = f64> = Quantity<Z0, Z0, Z0, P1, Z0, Z0, Z0, F>;
+/// Time (s), dimension T^1.
+pub type Time<F = f64> = Quantity<Z0, Z0, P1, Z0, Z0, Z0, Z0, F>;
+/// Electrical charge (C = A*s), dimension T^1 I^1.
+pub type Charge<F = f64> = Quantity<Z0, Z0, P1, P1, Z0, Z0, Z0, F>;
+/// Voltage (V = kg*m^2*s^-3*A^-1), dimension L^2 M^1 T^-3 I^-1.
+pub type Voltage<F = f64> = Quantity<P2, P1, N3, N1, Z0, Z0, Z0, F>;
+/// Energy (J = kg*m^2*s^-2), dimension L^2 M^1 T^-2.
+pub type Energy<F = f64> = Quantity<P2, P1, N2, Z0, Z0, Z0, Z0, F>;
+/// A dimensionless ratio - what's left when dividing two quantities that
+/// share a dimension (e.g. `Charge / Charge`), all seven exponents zero.
+pub type Ratio<F = f64> = Quantity<Z0, Z0, Z0, Z0, Z0, Z0, Z0, F>;
+
+impl<F: Float> Current<F> {
+    /// Create a new `Current` from a floating point value in amperes.
+    pub fn from_amperes(amperes: F) -> Self {
+        Quantity::from_base_units(amperes)
+    }
+
+    /// Convert this `Current` into a floating point value in amperes.
+    pub fn as_amperes(&self) -> F {
+        self.as_base_units()
+    }
+}
+
+impl<F: Float> Time<F> {
+    /// Create a new `Time` from a floating point value in seconds.
+    pub fn from_seconds(seconds: F) -> Self {
+        Quantity::from_base_units(seconds)
+    }
+
+    /// Convert this `Time` into a floating point value in seconds.
+    pub fn as_seconds(&self) -> F {
+        self.as_base_units()
+    }
+}
+
+impl<F: Float> Voltage<F> {
+    /// Create a new `Voltage` from a floating point value in volts.
+    pub fn from_volts(volts: F) -> Self {
+        Quantity::from_base_units(volts)
+    }
+
+    /// Convert this `Voltage` into a floating point value in volts.
+    pub fn as_volts(&self) -> F {
+        self.as_base_units()
+    }
+}
+
+impl<F: Float> Energy<F> {
+    /// Create a new `Energy` from a floating point value in joules.
+    pub fn from_joules(joules: F) -> Self {
+        Quantity::from_base_units(joules)
+    }
+
+    /// Convert this `Energy` into a floating point value in joules.
+    pub fn as_joules(&self) -> F {
+        self.as_base_units()
+    }
+}
+
+impl<F: Float> Ratio<F> {
+    /// The plain numeric value of a dimensionless `Quantity`, e.g. the
+    /// result of dividing two quantities that share a dimension.
+    pub fn as_ratio(&self) -> F {
+        self.as_base_units()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_utils::assert_almost_eq;
+
+    #[test]
+    fn current_times_time_is_charge() {
+        let i = Current::from_amperes(2.0);
+        let t = Time::from_seconds(3.0);
+        let q: Charge = i * t;
+        assert_almost_eq(q.as_coulombs(), 6.0);
+    }
+
+    #[test]
+    fn charge_divided_by_time_is_current() {
+        let q = Charge::from_coulombs(6.0);
+        let t = Time::from_seconds(3.0);
+        let i: Current = q / t;
+        assert_almost_eq(i.as_amperes(), 2.0);
+    }
+
+    #[test]
+    fn charge_times_voltage_is_energy() {
+        let q = Charge::from_coulombs(2.0);
+        let v = Voltage::from_volts(5.0);
+        let e: Energy = q * v;
+        assert_almost_eq(e.as_joules(), 10.0);
+    }
+
+    #[test]
+    fn f32_backed_quantity() {
+        let i: Current<f32> = Current::from_amperes(2.0f32);
+        let t: Time<f32> = Time::from_seconds(3.0f32);
+        let q: Charge<f32> = i * t;
+        assert_almost_eq(f64::from(q.as_coulombs()), 6.0);
+    }
+
+    #[test]
+    fn scalar_times_quantity() {
+        let t = Time::from_seconds(3.0);
+        let doubled: Time = 2.0 * t;
+        assert_almost_eq(doubled.as_seconds(), 6.0);
+    }
+
+    #[test]
+    fn same_dimension_division_is_a_ratio() {
+        let a = Time::from_seconds(6.0);
+        let b = Time::from_seconds(3.0);
+        let r: Ratio = a / b;
+        assert_almost_eq(r.as_ratio(), 2.0);
+    }
+}